@@ -0,0 +1,79 @@
+//! Function execution: binds a `Method` to a closure scope and receiver.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::method::Method;
+use crate::avm2::object::{ClassObject, Object};
+use crate::avm2::scope::Scope;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{Collect, GcCell, MutationContext};
+
+/// A `Method` bound to a closure `Scope` and (optionally) a fixed receiver,
+/// ready to be invoked as a function call.
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct Executable<'gc> {
+    /// The method being executed.
+    method: Method,
+
+    /// The scope this method closes over, if any.
+    scope: Option<GcCell<'gc, Scope<'gc>>>,
+
+    /// A receiver fixed at construction time, which overrides whatever
+    /// receiver the caller supplies.
+    receiver: Option<Object<'gc>>,
+}
+
+impl<'gc> Executable<'gc> {
+    pub fn from_method(
+        method: Method,
+        scope: Option<GcCell<'gc, Scope<'gc>>>,
+        receiver: Option<Object<'gc>>,
+        _mc: MutationContext<'gc, '_>,
+    ) -> Self {
+        Self {
+            method,
+            scope,
+            receiver,
+        }
+    }
+
+    /// The number of leading required parameters the underlying method
+    /// declares (see `Method::param_count`).
+    pub fn param_count(&self) -> usize {
+        self.method.param_count()
+    }
+
+    /// The declared name of the underlying method, if any.
+    ///
+    /// Returns an owned `String` (rather than borrowing from `self`) since
+    /// callers like `FunctionObject::to_string` only ever hold a cloned,
+    /// short-lived `Executable`.
+    pub fn method_name(&self) -> Option<String> {
+        self.method.method_name().map(ToOwned::to_owned)
+    }
+
+    pub fn exec(
+        &self,
+        receiver: Option<Object<'gc>>,
+        arguments: &[Value<'gc>],
+        activation: &mut Activation<'_, 'gc, '_>,
+        subclass_object: Option<ClassObject<'gc>>,
+        callee: Object<'gc>,
+    ) -> Result<Value<'gc>, Error> {
+        let receiver = self.receiver.or(receiver);
+
+        if let Some(native) = self.method.native() {
+            return native(activation, receiver, arguments);
+        }
+
+        activation.run_method(
+            &self.method,
+            self.scope,
+            receiver,
+            arguments,
+            subclass_object,
+            callee,
+        )
+    }
+}
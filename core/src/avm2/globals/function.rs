@@ -0,0 +1,64 @@
+//! `Function` global methods
+//!
+//! Installs the native methods `FunctionObject` implements (`call`,
+//! `apply`, `bind`) onto `Function.prototype`, so that AS3 code can
+//! actually reach them as `someFn.call(...)` etc. — `FunctionObject`
+//! itself only defines the Rust-level behavior.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::method::{Method, NativeMethodImpl, ParamConfig};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::function_object::{apply, bind, call};
+use crate::avm2::object::{FunctionObject, Object, TObject};
+use crate::avm2::Error;
+
+/// A list of (name, native impl, required parameter count) triples, mirroring
+/// the real player's declared `Function.prototype` signatures.
+const PUBLIC_PROTOTYPE_METHODS: &[(&str, NativeMethodImpl, usize)] =
+    &[("call", call, 1), ("apply", apply, 2), ("bind", bind, 1)];
+
+/// Install `call`, `apply`, and `bind` onto `Function.prototype`.
+pub fn fill_proto<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut proto: Object<'gc>,
+) -> Result<(), Error> {
+    for (name, method, num_required_params) in PUBLIC_PROTOTYPE_METHODS {
+        let params = vec![ParamConfig { is_optional: false }; *num_required_params];
+        let method_object = FunctionObject::from_method(
+            activation,
+            Method::from_builtin(*method, name, params),
+            None,
+            None,
+        );
+
+        proto.install_slot(
+            activation.context.gc_context,
+            QName::new(Namespace::public(), *name),
+            0,
+            method_object.into(),
+            false,
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prototype_methods_match_the_player_declared_signatures() {
+        let names: Vec<&str> = PUBLIC_PROTOTYPE_METHODS
+            .iter()
+            .map(|(name, ..)| *name)
+            .collect();
+        assert_eq!(names, ["call", "apply", "bind"]);
+
+        let required_params: Vec<usize> = PUBLIC_PROTOTYPE_METHODS
+            .iter()
+            .map(|(_, _, count)| *count)
+            .collect();
+        assert_eq!(required_params, [1, 2, 1]);
+    }
+}
@@ -0,0 +1,20 @@
+//! Global builtin classes and objects.
+
+mod function;
+
+use crate::avm2::activation::Activation;
+use crate::avm2::Error;
+
+/// Populate the builtin prototypes (`Function.prototype`, ...) with their
+/// native methods.
+///
+/// This is the call site `avm2::globals::function::fill_proto` needs to
+/// ever run: without it, `Function.prototype.call/apply/bind` exist as
+/// Rust functions but are never reachable from AS3.
+pub fn load_player_globals<'gc>(activation: &mut Activation<'_, 'gc, '_>) -> Result<(), Error> {
+    let function_proto = activation.avm2().prototypes().function;
+
+    function::fill_proto(activation, function_proto)?;
+
+    Ok(())
+}
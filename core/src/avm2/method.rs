@@ -0,0 +1,129 @@
+//! Method metadata: declared parameter signatures and native entry points.
+//!
+//! `Method` only carries what `FunctionObject` needs to reflect a function's
+//! signature back to AS3 (`length`, ...) — the method body itself is read
+//! by the interpreter.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::Object;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::Collect;
+use std::rc::Rc;
+
+/// A native function that backs a `Method`.
+pub type NativeMethodImpl = for<'gc> fn(
+    &mut Activation<'_, 'gc, '_>,
+    Option<Object<'gc>>,
+    &[Value<'gc>],
+) -> Result<Value<'gc>, Error>;
+
+/// One declared parameter, in source order. Does not cover a trailing
+/// `...rest` parameter, which never counts towards `length`.
+#[derive(Clone, Debug)]
+pub struct ParamConfig {
+    /// Whether this parameter has a default value (and is thus optional).
+    pub is_optional: bool,
+}
+
+#[derive(Clone, Debug)]
+struct MethodData {
+    name: Option<Rc<str>>,
+    params: Rc<[ParamConfig]>,
+    native: Option<NativeMethodImpl>,
+}
+
+/// A reference to an AVM2 method: its declared signature and body.
+#[derive(Clone, Debug)]
+pub struct Method(Rc<MethodData>);
+
+unsafe impl Collect for Method {
+    fn needs_trace() -> bool {
+        false
+    }
+}
+
+impl Method {
+    /// Construct a method backed by a native Rust function.
+    pub fn from_builtin(native: NativeMethodImpl, name: &str, params: Vec<ParamConfig>) -> Self {
+        Self(Rc::new(MethodData {
+            name: Some(Rc::from(name)),
+            params: params.into(),
+            native: Some(native),
+        }))
+    }
+
+    /// Construct a method backed by ABC bytecode, with its declared name
+    /// (if any) and parameter signature.
+    pub fn from_abc(name: Option<&str>, params: Vec<ParamConfig>) -> Self {
+        Self(Rc::new(MethodData {
+            name: name.map(Rc::from),
+            params: params.into(),
+            native: None,
+        }))
+    }
+
+    /// The number of leading required (non-optional) declared parameters,
+    /// counted up to the first optional one, matching the semantics of
+    /// ECMAScript/AVM2 `Function.prototype.length`.
+    pub fn param_count(&self) -> usize {
+        self.0
+            .params
+            .iter()
+            .take_while(|param| !param.is_optional)
+            .count()
+    }
+
+    pub(crate) fn native(&self) -> Option<NativeMethodImpl> {
+        self.0.native
+    }
+
+    /// The name this method was declared with, or `None` for an anonymous
+    /// function expression.
+    pub fn method_name(&self) -> Option<&str> {
+        self.0.name.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required(count: usize) -> Vec<ParamConfig> {
+        std::iter::repeat(ParamConfig { is_optional: false })
+            .take(count)
+            .collect()
+    }
+
+    #[test]
+    fn param_count_stops_at_first_optional_param() {
+        let mut params = required(2);
+        params.push(ParamConfig { is_optional: true });
+        params.push(ParamConfig { is_optional: false });
+
+        let method = Method::from_abc(None, params);
+
+        assert_eq!(method.param_count(), 2);
+    }
+
+    #[test]
+    fn param_count_counts_every_required_param() {
+        let method = Method::from_abc(None, required(3));
+
+        assert_eq!(method.param_count(), 3);
+    }
+
+    #[test]
+    fn method_name_is_none_for_anonymous_methods() {
+        let method = Method::from_abc(None, Vec::new());
+
+        assert_eq!(method.method_name(), None);
+    }
+
+    #[test]
+    fn method_name_reports_the_declared_name() {
+        let method = Method::from_abc(Some("greet"), Vec::new());
+
+        assert_eq!(method.method_name(), Some("greet"));
+    }
+}
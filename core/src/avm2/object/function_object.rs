@@ -10,6 +10,7 @@ use crate::avm2::scope::Scope;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use gc_arena::{Collect, GcCell, MutationContext};
+use std::borrow::Cow;
 use std::cell::{Ref, RefMut};
 
 /// An Object which can be called to execute its function code.
@@ -25,6 +26,16 @@ pub struct FunctionObjectData<'gc> {
 
     /// Executable code
     exec: Option<Executable<'gc>>,
+
+    /// The receiver that a bound function will supply in place of any
+    /// receiver provided by the caller, if any.
+    ///
+    /// Only set for functions produced by `Function.prototype.bind`.
+    bound_receiver: Option<Object<'gc>>,
+
+    /// Arguments that a bound function will prepend to the arguments
+    /// provided by the caller.
+    bound_arguments: Vec<Value<'gc>>,
 }
 
 impl<'gc> FunctionObject<'gc> {
@@ -34,7 +45,7 @@ impl<'gc> FunctionObject<'gc> {
     /// `Object` prototype for the function.
     pub fn from_function(
         activation: &mut Activation<'_, 'gc, '_>,
-        method: Method<'gc>,
+        method: Method,
         scope: Option<GcCell<'gc, Scope<'gc>>>,
     ) -> Result<Object<'gc>, Error> {
         let mut this = Self::from_method(activation, method, scope, None);
@@ -60,11 +71,12 @@ impl<'gc> FunctionObject<'gc> {
     /// `this` parameter.
     pub fn from_method(
         activation: &mut Activation<'_, 'gc, '_>,
-        method: Method<'gc>,
+        method: Method,
         scope: Option<GcCell<'gc, Scope<'gc>>>,
         receiver: Option<Object<'gc>>,
     ) -> Object<'gc> {
         let fn_proto = activation.avm2().prototypes().function;
+        let num_parameters = method.param_count();
         let exec = Some(Executable::from_method(
             method,
             scope,
@@ -72,17 +84,260 @@ impl<'gc> FunctionObject<'gc> {
             activation.context.gc_context,
         ));
 
-        FunctionObject(GcCell::allocate(
+        let mut this: Object<'gc> = FunctionObject(GcCell::allocate(
             activation.context.gc_context,
             FunctionObjectData {
                 base: ScriptObjectData::base_new(Some(fn_proto), None),
                 exec,
+                bound_receiver: None,
+                bound_arguments: Vec::new(),
             },
         ))
-        .into()
+        .into();
+
+        this.install_slot(
+            activation.context.gc_context,
+            QName::new(Namespace::public(), "length"),
+            0,
+            (num_parameters as u32).into(),
+            false,
+        );
+
+        this
+    }
+
+    /// Construct a bound method from an object and a set of bound arguments.
+    ///
+    /// The returned function permanently overrides its receiver with
+    /// `bound_receiver` (if any is given) and prepends `bound_arguments` to
+    /// every invocation, mirroring `Function.prototype.bind`. It also
+    /// shares `bound_method`'s `prototype`, so that `new boundFn()` derives
+    /// against the original function's prototype instead of failing to
+    /// find one at all, and its `length` shrinks by however many arguments
+    /// this particular `bind()` call prepends.
+    ///
+    /// If `bound_method` is itself already a bound function, this composes
+    /// with its existing binding rather than discarding it: the receiver
+    /// stays fixed at the original binding, and bound arguments accumulate
+    /// (`f.bind(a, 1, 2).bind(b, 3, 4)` ends up bound to `a` with
+    /// `[1, 2, 3, 4]`, not to `b` with just `[3, 4]`).
+    pub fn from_bound_method(
+        activation: &mut Activation<'_, 'gc, '_>,
+        bound_method: Object<'gc>,
+        bound_receiver: Option<Object<'gc>>,
+        bound_arguments: Vec<Value<'gc>>,
+    ) -> Object<'gc> {
+        let fn_proto = activation.avm2().prototypes().function;
+
+        let prototype = bound_method
+            .get_property(
+                bound_method,
+                &QName::new(Namespace::public(), "prototype").into(),
+                activation,
+            )
+            .ok();
+
+        let existing_length = bound_method
+            .get_property(
+                bound_method,
+                &QName::new(Namespace::public(), "length").into(),
+                activation,
+            )
+            .ok()
+            .and_then(|value| value.coerce_to_u32(activation).ok())
+            .unwrap_or(0);
+
+        let (existing_receiver, existing_arguments, exec) =
+            if let Object::FunctionObject(inner) = bound_method {
+                let read = inner.0.read();
+                (
+                    read.bound_receiver,
+                    read.bound_arguments.clone(),
+                    read.exec.clone(),
+                )
+            } else {
+                (None, Vec::new(), bound_method.as_executable())
+            };
+
+        let (bound_receiver, bound_arguments, new_length) = compose_binding(
+            existing_receiver,
+            existing_arguments,
+            existing_length,
+            bound_receiver,
+            bound_arguments,
+        );
+
+        let mut this: Object<'gc> = FunctionObject(GcCell::allocate(
+            activation.context.gc_context,
+            FunctionObjectData {
+                base: ScriptObjectData::base_new(Some(fn_proto), None),
+                exec,
+                bound_receiver,
+                bound_arguments,
+            },
+        ))
+        .into();
+
+        if let Some(prototype) = prototype {
+            this.install_slot(
+                activation.context.gc_context,
+                QName::new(Namespace::public(), "prototype"),
+                0,
+                prototype,
+                false,
+            );
+        }
+
+        this.install_slot(
+            activation.context.gc_context,
+            QName::new(Namespace::public(), "length"),
+            0,
+            new_length.into(),
+            false,
+        );
+
+        this
     }
 }
 
+/// Works out the receiver, arguments, and `length` a `bind()` call
+/// produces, given whatever binding (if any) `bound_method` already
+/// carried and what this particular call supplies.
+///
+/// Kept generic over the receiver/argument types so the composition rules
+/// — the first `bind()`'s receiver wins, and each successive `bind()` only
+/// shrinks `length` by the arguments *it* prepends — can be unit tested
+/// without an `Activation`/`Object` to construct.
+fn compose_binding<R, V>(
+    existing_receiver: Option<R>,
+    existing_arguments: Vec<V>,
+    existing_length: u32,
+    new_receiver: Option<R>,
+    new_arguments: Vec<V>,
+) -> (Option<R>, Vec<V>, u32) {
+    let receiver = existing_receiver.or(new_receiver);
+
+    let prepended = new_arguments.len() as u32;
+    let mut arguments = existing_arguments;
+    arguments.extend(new_arguments);
+
+    let length = existing_length.saturating_sub(prepended);
+
+    (receiver, arguments, length)
+}
+
+/// Prepend `bound_arguments` to a call-site argument list, avoiding the
+/// allocation entirely when there is nothing to prepend.
+fn prepend_bound_arguments<'gc, 'a>(
+    bound_arguments: &[Value<'gc>],
+    arguments: &'a [Value<'gc>],
+) -> Cow<'a, [Value<'gc>]> {
+    if bound_arguments.is_empty() {
+        Cow::Borrowed(arguments)
+    } else {
+        let mut all_arguments = bound_arguments.to_vec();
+        all_arguments.extend_from_slice(arguments);
+        Cow::Owned(all_arguments)
+    }
+}
+
+/// `Function.prototype.bind`
+pub fn bind<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    arguments: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.ok_or("Function.prototype.bind called on a non-object receiver")?;
+    let bound_receiver = arguments
+        .get(0)
+        .map(|value| value.coerce_to_object(activation))
+        .transpose()?;
+    let bound_arguments = arguments.get(1..).unwrap_or(&[]).to_vec();
+
+    Ok(FunctionObject::from_bound_method(activation, this, bound_receiver, bound_arguments).into())
+}
+
+/// `Function.prototype.call`
+pub fn call<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    arguments: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.ok_or("Function.prototype.call called on a non-object receiver")?;
+    let receiver = arguments
+        .get(0)
+        .map(|value| value.coerce_to_object(activation))
+        .transpose()?;
+    let call_arguments = arguments.get(1..).unwrap_or(&[]);
+
+    this.call(receiver, call_arguments, activation, None)
+}
+
+/// The largest array-like `length` `Function.prototype.apply` will honor.
+///
+/// Without a cap, a caller passing a huge (or forged) `length` would drive
+/// an equally huge `Vec::with_capacity` allocation before a single element
+/// is read. This bound exists purely to keep that allocation sane; it
+/// isn't sourced from any documented player limit, just a value far above
+/// any argument list a real call site would plausibly construct.
+const MAX_APPLY_ARGUMENTS_LENGTH: u32 = 0xFFFFFF;
+
+/// Rejects an `apply()` array-like `length` that would blow past
+/// [`MAX_APPLY_ARGUMENTS_LENGTH`], before it's used to size an allocation.
+fn validate_apply_length(length: u32) -> Result<(), Error> {
+    if length > MAX_APPLY_ARGUMENTS_LENGTH {
+        return Err(format!(
+            "Function.prototype.apply: argument array length {} exceeds the maximum of {}",
+            length, MAX_APPLY_ARGUMENTS_LENGTH
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// `Function.prototype.apply`
+pub fn apply<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    arguments: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let this = this.ok_or("Function.prototype.apply called on a non-object receiver")?;
+    let receiver = arguments
+        .get(0)
+        .map(|value| value.coerce_to_object(activation))
+        .transpose()?;
+
+    let call_arguments = match arguments.get(1) {
+        None | Some(Value::Undefined) | Some(Value::Null) => Vec::new(),
+        Some(array_like) => {
+            let array_like = array_like.coerce_to_object(activation)?;
+            let length = array_like
+                .get_property(
+                    array_like,
+                    &QName::new(Namespace::public(), "length").into(),
+                    activation,
+                )?
+                .coerce_to_u32(activation)?;
+
+            validate_apply_length(length)?;
+
+            let mut call_arguments = Vec::with_capacity(length as usize);
+            for i in 0..length {
+                call_arguments.push(array_like.get_property(
+                    array_like,
+                    &QName::new(Namespace::public(), &i.to_string()).into(),
+                    activation,
+                )?);
+            }
+
+            call_arguments
+        }
+    };
+
+    this.call(receiver, &call_arguments, activation, None)
+}
+
 impl<'gc> TObject<'gc> for FunctionObject<'gc> {
     fn base(&self) -> Ref<ScriptObjectData<'gc>> {
         Ref::map(self.0.read(), |read| &read.base)
@@ -97,7 +352,12 @@ impl<'gc> TObject<'gc> for FunctionObject<'gc> {
     }
 
     fn to_string(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
-        Ok("function Function() {}".into())
+        let name = self
+            .as_executable()
+            .and_then(|exec| exec.method_name())
+            .unwrap_or_else(|| "Function".to_string());
+
+        Ok(format!("function {}() {{}}", name).into())
     }
 
     fn to_locale_string(&self, mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
@@ -119,10 +379,14 @@ impl<'gc> TObject<'gc> for FunctionObject<'gc> {
         activation: &mut Activation<'_, 'gc, '_>,
         subclass_object: Option<ClassObject<'gc>>,
     ) -> Result<Value<'gc>, Error> {
-        if let Some(exec) = &self.0.read().exec {
+        let read = self.0.read();
+        if let Some(exec) = &read.exec {
+            let receiver = read.bound_receiver.or(receiver);
+            let arguments = prepend_bound_arguments(&read.bound_arguments, arguments);
+
             exec.exec(
                 receiver,
-                arguments,
+                &arguments,
                 activation,
                 subclass_object,
                 self.into(),
@@ -148,7 +412,17 @@ impl<'gc> TObject<'gc> for FunctionObject<'gc> {
 
         let instance = prototype.derive(activation)?;
 
-        self.call(Some(instance), arguments, activation, None)?;
+        // Unlike a regular call, a bound function's receiver is always
+        // discarded in favor of the freshly-derived instance; only the
+        // bound arguments still apply.
+        let read = self.0.read();
+        if let Some(exec) = &read.exec {
+            let arguments = prepend_bound_arguments(&read.bound_arguments, arguments);
+
+            exec.exec(Some(instance), &arguments, activation, None, self.into())?;
+        } else {
+            return Err("Not a callable function!".into());
+        }
 
         Ok(instance)
     }
@@ -159,8 +433,105 @@ impl<'gc> TObject<'gc> for FunctionObject<'gc> {
 
         Ok(FunctionObject(GcCell::allocate(
             activation.context.gc_context,
-            FunctionObjectData { base, exec: None },
+            FunctionObjectData {
+                base,
+                exec: None,
+                bound_receiver: None,
+                bound_arguments: Vec::new(),
+            },
         ))
         .into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_accepts_array_lengths_at_the_cutoff() {
+        assert!(validate_apply_length(MAX_APPLY_ARGUMENTS_LENGTH).is_ok());
+    }
+
+    #[test]
+    fn apply_rejects_array_lengths_past_the_cutoff() {
+        assert!(validate_apply_length(MAX_APPLY_ARGUMENTS_LENGTH + 1).is_err());
+    }
+
+    #[test]
+    fn compose_binding_first_bind_receiver_wins() {
+        let (receiver, _arguments, _length) =
+            compose_binding(Some("a"), Vec::<i32>::new(), 0, Some("b"), Vec::new());
+
+        assert_eq!(receiver, Some("a"));
+    }
+
+    #[test]
+    fn compose_binding_falls_back_to_new_receiver_when_unbound() {
+        let (receiver, _arguments, _length) =
+            compose_binding(None, Vec::<i32>::new(), 0, Some("b"), Vec::new());
+
+        assert_eq!(receiver, Some("b"));
+    }
+
+    #[test]
+    fn compose_binding_accumulates_arguments_in_call_order() {
+        let (_receiver, arguments, _length) =
+            compose_binding::<(), _>(None, vec![1, 2], 0, None, vec![3, 4]);
+
+        assert_eq!(arguments, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn compose_binding_chained_length_matches_total_prepended_args() {
+        // f.bind(x, 1, 2) -- target.length starts at 5.
+        let (receiver, arguments, length) =
+            compose_binding(None, Vec::new(), 5, Some("x"), vec![1, 2]);
+        assert_eq!(receiver, Some("x"));
+        assert_eq!(arguments, vec![1, 2]);
+        assert_eq!(length, 3);
+
+        // .bind(y, 3, 4) on the already-bound result above.
+        let (receiver, arguments, length) =
+            compose_binding(receiver, arguments, length, Some("y"), vec![3, 4]);
+        assert_eq!(receiver, Some("x"));
+        assert_eq!(arguments, vec![1, 2, 3, 4]);
+        assert_eq!(length, 1);
+    }
+
+    #[test]
+    fn compose_binding_length_does_not_underflow() {
+        let (_receiver, _arguments, length) =
+            compose_binding::<(), _>(None, Vec::new(), 1, None, vec![1, 2, 3]);
+
+        assert_eq!(length, 0);
+    }
+
+    #[test]
+    fn prepend_bound_arguments_borrows_when_nothing_to_prepend() {
+        let arguments = [Value::Integer(1), Value::Integer(2)];
+
+        let result = prepend_bound_arguments(&[], &arguments);
+
+        assert!(matches!(result, Cow::Borrowed(_)));
+        assert_eq!(&*result, &arguments);
+    }
+
+    #[test]
+    fn prepend_bound_arguments_puts_bound_args_first() {
+        let bound = [Value::Integer(1), Value::Integer(2)];
+        let call_site = [Value::Integer(3), Value::Integer(4)];
+
+        let result = prepend_bound_arguments(&bound, &call_site);
+
+        assert_eq!(
+            &*result,
+            &[
+                Value::Integer(1),
+                Value::Integer(2),
+                Value::Integer(3),
+                Value::Integer(4),
+            ]
+        );
+    }
+}